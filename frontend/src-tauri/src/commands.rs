@@ -0,0 +1,92 @@
+//! Tauri commands invoked from the frontend (and, for `type_into_active_window`,
+//! from the `aio://type` deep link handler).
+
+use std::thread;
+use std::time::Duration;
+
+/// Upper bound on the configurable delays below. Both are taken straight
+/// from deep-link query params, so without a cap a crafted link could tie
+/// up a blocking-pool thread indefinitely.
+const MAX_DELAY_MS: u64 = 5_000;
+
+/// Loads `text` into the clipboard, switches focus back to whatever window
+/// was active before AIO's, and pastes it there. This is how a
+/// `aio://type?...` deep link (e.g. fired from a global shortcut) can drop a
+/// computed result back into whatever the user was typing in.
+///
+/// `focus_delay_ms` and `paste_delay_ms` are exposed because some target
+/// apps are slow to respond to synthetic focus/paste events; callers with a
+/// flaky target can widen them, up to `MAX_DELAY_MS`. The clipboard/keystroke
+/// work is blocking, so it runs on a dedicated blocking thread rather than
+/// the async runtime's worker threads.
+#[tauri::command]
+pub async fn type_into_active_window(text: String, focus_delay_ms: u64, paste_delay_ms: u64) {
+    let focus_delay_ms = focus_delay_ms.min(MAX_DELAY_MS);
+    let paste_delay_ms = paste_delay_ms.min(MAX_DELAY_MS);
+
+    if let Err(err) = tauri::async_runtime::spawn_blocking(move || {
+        copy_and_paste(text, focus_delay_ms, paste_delay_ms)
+    })
+    .await
+    {
+        println!("type_into_active_window: blocking task failed: {err}");
+    }
+}
+
+/// Does the actual clipboard/keystroke work. Blocking, so callers must run
+/// it off the async runtime's worker threads (e.g. via `spawn_blocking`).
+fn copy_and_paste(text: String, focus_delay_ms: u64, paste_delay_ms: u64) {
+    let mut clipboard = match arboard::Clipboard::new() {
+        Ok(clipboard) => clipboard,
+        Err(err) => {
+            println!("type_into_active_window: clipboard unavailable: {err}");
+            return;
+        }
+    };
+
+    if let Err(err) = clipboard.set_text(text.clone()) {
+        println!("type_into_active_window: failed to set clipboard text: {err}");
+        return;
+    }
+
+    #[cfg(not(debug_assertions))]
+    inject_paste(focus_delay_ms, paste_delay_ms);
+
+    #[cfg(debug_assertions)]
+    {
+        let _ = (focus_delay_ms, paste_delay_ms);
+        println!("type_into_active_window (debug build, clipboard only): {text}");
+    }
+}
+
+/// Alt+Tabs back to the previously active window, then sends the platform
+/// paste chord. Real keystroke injection is only wired up in release
+/// builds so debug runs never hijack the developer's focus.
+#[cfg(not(debug_assertions))]
+fn inject_paste(focus_delay_ms: u64, paste_delay_ms: u64) {
+    use enigo::{Direction, Enigo, Key, Keyboard, Settings};
+
+    let mut enigo = match Enigo::new(&Settings::default()) {
+        Ok(enigo) => enigo,
+        Err(err) => {
+            println!("type_into_active_window: synthetic input unavailable: {err}");
+            return;
+        }
+    };
+
+    let _ = enigo.key(Key::Alt, Direction::Press);
+    let _ = enigo.key(Key::Tab, Direction::Click);
+    let _ = enigo.key(Key::Alt, Direction::Release);
+
+    thread::sleep(Duration::from_millis(focus_delay_ms));
+
+    let paste_modifier = if cfg!(target_os = "macos") {
+        Key::Meta
+    } else {
+        Key::Control
+    };
+    let _ = enigo.key(paste_modifier, Direction::Press);
+    thread::sleep(Duration::from_millis(paste_delay_ms));
+    let _ = enigo.key(Key::Unicode('v'), Direction::Click);
+    let _ = enigo.key(paste_modifier, Direction::Release);
+}
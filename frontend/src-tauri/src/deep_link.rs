@@ -0,0 +1,272 @@
+//! Parsing and dispatch for `aio://` deep links.
+//!
+//! An incoming link is parsed into a [`DeepLink`] (action + path segments +
+//! query params), matched against the set of actions this build knows about,
+//! and handed off to an async handler on `tauri::async_runtime`. Handlers that don't
+//! touch the UI run headless; UI-facing ones emit to the frontend and
+//! focus the main window.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager, State};
+
+/// A deep link parsed from an `aio://action/seg1/seg2?key=value` string.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct DeepLink {
+    pub action: String,
+    pub path: Vec<String>,
+    pub params: HashMap<String, String>,
+}
+
+impl DeepLink {
+    /// Parses a raw `aio://...` string into a structured deep link.
+    ///
+    /// The host segment is the action, any further `/`-separated segments
+    /// become `path`, and the query string is decoded into `params`.
+    /// Returns `None` if `raw` isn't an `aio://` URL.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let rest = raw.strip_prefix("aio://")?;
+        let (head, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let mut segments = head.split('/').filter(|s| !s.is_empty());
+        let action = segments.next().unwrap_or("").to_string();
+        if action.is_empty() {
+            return None;
+        }
+        let path = segments.map(percent_decode).collect();
+
+        let mut params = HashMap::new();
+        for pair in query.split('&').filter(|s| !s.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            params.insert(percent_decode(key), percent_decode(value));
+        }
+
+        Some(DeepLink {
+            action,
+            path,
+            params,
+        })
+    }
+}
+
+/// Actions this build of AIO knows how to handle. Keep this in sync with
+/// [`dispatch`]'s match arms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    /// Imports data in the background; never touches the UI.
+    Import,
+    /// Completes an OAuth-style flow in the background.
+    AuthCallback,
+    /// Opens something in the main window and brings it to front.
+    Open,
+    /// Types `text` into whichever window was focused before AIO's. Runs
+    /// headless: it acts on another app, not this one's UI.
+    Type,
+}
+
+impl Action {
+    fn parse(action: &str) -> Option<Self> {
+        match action {
+            "import" => Some(Action::Import),
+            "auth-callback" => Some(Action::AuthCallback),
+            "open" => Some(Action::Open),
+            "type" => Some(Action::Type),
+            _ => None,
+        }
+    }
+
+    /// Whether this action is handled by the frontend rather than entirely
+    /// in Rust. Only these need to be buffered for `drain_pending_deep_links`
+    /// — `Import`/`AuthCallback`/`Type` run to completion headlessly and
+    /// have nothing left for the frontend to do.
+    fn needs_ui(self) -> bool {
+        matches!(self, Action::Open)
+    }
+}
+
+/// Default delay, in milliseconds, between returning focus to the target
+/// window and sending the paste chord. Overridable via the `focus_delay_ms`
+/// query param for target apps that are slow to regain focus.
+const DEFAULT_FOCUS_DELAY_MS: u64 = 150;
+
+/// Default delay, in milliseconds, between holding the paste modifier and
+/// pressing `v`. Overridable via the `paste_delay_ms` query param.
+const DEFAULT_PASTE_DELAY_MS: u64 = 50;
+
+/// Validates and routes a parsed deep link to its handler, spawned on
+/// `tauri::async_runtime` so callers (the `setup` hook, the
+/// `single_instance` callback) never block on it.
+pub fn dispatch(app: &AppHandle, link: DeepLink) {
+    let Some(action) = Action::parse(&link.action) else {
+        println!("Ignoring deep link with unknown action: {}", link.action);
+        return;
+    };
+
+    // Only UI-facing links need to survive for the frontend to drain later;
+    // headless actions are fully handled below and must never be replayed.
+    if action.needs_ui() {
+        buffer(app, link.clone());
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match action {
+            Action::Import => handle_import(&app, link.params).await,
+            Action::AuthCallback => handle_auth_callback(&app, link.params).await,
+            Action::Open => handle_open(&app, link).await,
+            Action::Type => handle_type(link.params).await,
+        }
+    });
+}
+
+/// Imports whatever `params` describes. Runs headless: no window is
+/// required, so this can complete even if the UI never opens.
+async fn handle_import(_app: &AppHandle, params: HashMap<String, String>) {
+    println!("Handling import deep link: {:?}", params);
+}
+
+/// Finishes an auth flow started outside the app. Runs headless.
+async fn handle_auth_callback(_app: &AppHandle, params: HashMap<String, String>) {
+    println!("Handling auth-callback deep link: {:?}", params);
+}
+
+/// Surfaces the link to the frontend and focuses the main window. Emits the
+/// same structured shape that `drain_pending_deep_links` returns, so the
+/// frontend has one `DeepLink` shape to handle regardless of whether it
+/// arrived live or was replayed from the buffer.
+async fn handle_open(app: &AppHandle, link: DeepLink) {
+    if let Some(window) = app.get_window("main") {
+        let _ = window.emit("deep-link", &link);
+        let _ = window.set_focus();
+    }
+}
+
+/// Types `text` into whatever window was active before AIO's. Lets a
+/// global-shortcut-triggered `aio://type?text=...` link hand a computed
+/// result back to whatever the user was doing.
+async fn handle_type(params: HashMap<String, String>) {
+    let Some(text) = params.get("text").cloned() else {
+        println!("Ignoring type deep link with no `text` param");
+        return;
+    };
+    let focus_delay_ms = params
+        .get("focus_delay_ms")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_FOCUS_DELAY_MS);
+    let paste_delay_ms = params
+        .get("paste_delay_ms")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_PASTE_DELAY_MS);
+
+    crate::commands::type_into_active_window(text, focus_delay_ms, paste_delay_ms).await;
+}
+
+/// UI-facing deep links kept for the frontend to drain, so that links that
+/// arrive before it installs its `deep-link` listener are not lost. Every
+/// UI-facing link `dispatch` routes is pushed here, including ones that are
+/// also emitted live after the listener is up. Managed as Tauri state so both the
+/// `setup` hook and the `single_instance` callback can push into it
+/// regardless of which one fires first. Headless actions are never
+/// buffered here — `dispatch` already ran them to completion, and
+/// replaying them would rerun side effects like `import`.
+#[derive(Default)]
+pub struct PendingDeepLinks(Mutex<Vec<DeepLink>>);
+
+/// Buffers `link` so it can be replayed once the frontend is ready.
+fn buffer(app: &AppHandle, link: DeepLink) {
+    let pending = app.state::<PendingDeepLinks>();
+    pending.0.lock().unwrap().push(link);
+}
+
+/// Returns every UI-facing deep link buffered since the last drain,
+/// clearing the buffer. The frontend calls this once its `deep-link`
+/// listener is installed so no launch URL is lost to init-order timing.
+#[tauri::command]
+pub fn drain_pending_deep_links(pending: State<'_, PendingDeepLinks>) -> Vec<DeepLink> {
+    std::mem::take(&mut *pending.0.lock().unwrap())
+}
+
+/// Minimal `application/x-www-form-urlencoded` decoder: turns `+` into a
+/// space and `%XX` into the byte it encodes, leaving anything else as-is.
+/// Malformed `%` escapes (not followed by two hex digits) are passed
+/// through verbatim rather than dropped.
+fn percent_decode(value: &str) -> String {
+    let mut out = Vec::with_capacity(value.len());
+    let bytes: Vec<u8> = value.bytes().collect();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        match b {
+            b'+' => {
+                out.push(b' ');
+                i += 1;
+            }
+            b'%' => {
+                let hi = bytes.get(i + 1).and_then(|b| (*b as char).to_digit(16));
+                let lo = bytes.get(i + 2).and_then(|b| (*b as char).to_digit(16));
+                match (hi, lo) {
+                    (Some(hi), Some(lo)) => {
+                        out.push((hi * 16 + lo) as u8);
+                        i += 3;
+                    }
+                    _ => {
+                        // Not a valid escape: emit the `%` and leave the
+                        // bytes after it for the next iteration to handle.
+                        out.push(b'%');
+                        i += 1;
+                    }
+                }
+            }
+            _ => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent_decode_handles_plus_and_escapes() {
+        assert_eq!(percent_decode("a+b"), "a b");
+        assert_eq!(percent_decode("50%25+off"), "50% off");
+    }
+
+    #[test]
+    fn percent_decode_passes_through_malformed_escapes() {
+        assert_eq!(percent_decode("50% off"), "50% off");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz"), "%zz");
+    }
+
+    #[test]
+    fn parse_splits_action_path_and_params() {
+        let link = DeepLink::parse("aio://open/foo/bar?a=1&b=2").unwrap();
+        assert_eq!(link.action, "open");
+        assert_eq!(link.path, vec!["foo", "bar"]);
+        assert_eq!(link.params.get("a").map(String::as_str), Some("1"));
+        assert_eq!(link.params.get("b").map(String::as_str), Some("2"));
+    }
+
+    #[test]
+    fn parse_rejects_non_aio_urls() {
+        assert!(DeepLink::parse("https://example.com").is_none());
+        assert!(DeepLink::parse("aio://").is_none());
+    }
+
+    #[test]
+    fn action_parse_and_needs_ui() {
+        assert_eq!(Action::parse("open"), Some(Action::Open));
+        assert_eq!(Action::parse("import"), Some(Action::Import));
+        assert_eq!(Action::parse("bogus"), None);
+
+        assert!(Action::Open.needs_ui());
+        assert!(!Action::Import.needs_ui());
+        assert!(!Action::AuthCallback.needs_ui());
+        assert!(!Action::Type.needs_ui());
+    }
+}
@@ -0,0 +1,75 @@
+//! Shared app bootstrap, used by both the desktop `main.rs` binary and the
+//! mobile entry point below.
+
+mod commands;
+mod deep_link;
+
+use deep_link::DeepLink;
+#[cfg(mobile)]
+use tauri_plugin_deep_link::DeepLinkExt;
+
+/// Builds and runs the Tauri app. Desktop argv/single-instance handling and
+/// mobile URL-scheme handling are `#[cfg]`-gated so each platform only pulls
+/// in the plugin it actually needs.
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    let builder = tauri::Builder::default().manage(deep_link::PendingDeepLinks::default());
+
+    #[cfg(desktop)]
+    let builder = builder
+        .plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
+            println!("Second instance detected with args: {:?}", argv);
+
+            // Find the deep link among the forwarded arguments and route it.
+            for arg in argv.iter() {
+                if let Some(link) = DeepLink::parse(arg) {
+                    println!("Deep link from second instance: {}", arg);
+                    deep_link::dispatch(app, link);
+                    break;
+                }
+            }
+        }))
+        .setup(|app| {
+            // Check if the app was launched with a deep link URL.
+            let args: Vec<String> = std::env::args().collect();
+            for arg in args.iter() {
+                if let Some(link) = DeepLink::parse(arg) {
+                    println!("Deep link received on startup: {}", arg);
+                    deep_link::dispatch(app.handle(), link);
+                    break;
+                }
+            }
+
+            Ok(())
+        });
+
+    #[cfg(mobile)]
+    let builder = builder
+        .plugin(tauri_plugin_deep_link::init())
+        .setup(|app| {
+            // Android/iOS hand us URL-scheme launches through the deep-link
+            // plugin's runtime registration rather than process arguments.
+            #[cfg(target_os = "android")]
+            app.deep_link().register_all()?;
+
+            let handle = app.handle().clone();
+            app.deep_link().on_open_url(move |event| {
+                for url in event.urls() {
+                    if let Some(link) = DeepLink::parse(url.as_str()) {
+                        println!("Deep link received: {}", url);
+                        deep_link::dispatch(&handle, link);
+                    }
+                }
+            });
+
+            Ok(())
+        });
+
+    builder
+        .invoke_handler(tauri::generate_handler![
+            deep_link::drain_pending_deep_links,
+            commands::type_into_active_window,
+        ])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}